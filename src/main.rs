@@ -8,6 +8,15 @@ pub enum OperatorType {
     Multiply,
     Divide,
     Power,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    BitAnd,
+    BitOr,
+    BitXor,
 }
 
 impl fmt::Display for OperatorType {
@@ -18,14 +27,25 @@ impl fmt::Display for OperatorType {
             OperatorType::Multiply => write!(f, "*"),
             OperatorType::Divide => write!(f, "/"),
             OperatorType::Power => write!(f, "**"),
+            OperatorType::Equal => write!(f, "=="),
+            OperatorType::NotEqual => write!(f, "!="),
+            OperatorType::Less => write!(f, "<"),
+            OperatorType::LessEqual => write!(f, "<="),
+            OperatorType::Greater => write!(f, ">"),
+            OperatorType::GreaterEqual => write!(f, ">="),
+            OperatorType::BitAnd => write!(f, "&"),
+            OperatorType::BitOr => write!(f, "|"),
+            OperatorType::BitXor => write!(f, "^"),
         }
     }
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum TokenType {
-    Number(f32),
+    Number(f64),
     Operator(OperatorType),
+    LParen,
+    RParen,
 }
 
 impl fmt::Display for TokenType {
@@ -33,6 +53,8 @@ impl fmt::Display for TokenType {
         match self {
             TokenType::Number(n) => write!(f, "Number({})", n),
             TokenType::Operator(op) => write!(f, "Operator({})", op),
+            TokenType::LParen => write!(f, "LParen"),
+            TokenType::RParen => write!(f, "RParen"),
         }
     }
 }
@@ -55,97 +77,241 @@ impl<'a> fmt::Display for Token<'a> {
     }
 }
 
+/// Errors produced while scanning characters into tokens, as opposed to
+/// errors produced while parsing tokens into an expression.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LexerError {
+    IllegalChar { offset: usize, ch: char },
+    InvalidNumber { span: (usize, usize) },
+}
+
+impl fmt::Display for LexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LexerError::IllegalChar { offset, ch } => {
+                write!(f, "unexpected character '{}' at byte {}", ch, offset)
+            }
+            LexerError::InvalidNumber { span } => {
+                write!(f, "invalid number literal at bytes {}..{}", span.0, span.1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LexerError {}
+
 pub struct Tokenizer<'a> {
     input: &'a str,
-    index: usize,
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
 }
 
 impl<'a> Iterator for Tokenizer<'a> {
-    type Item = Token<'a>;
+    type Item = Result<Token<'a>, LexerError>;
 
     fn next(&mut self) -> Option<Self::Item> {
         // Skip whitespace
-        while self.index < self.input.len()
-            && self.input.chars().nth(self.index).unwrap().is_whitespace()
-        {
-            self.index += 1;
-        }
-
-        if self.index >= self.input.len() {
-            return None;
+        while let Some(&(_, c)) = self.chars.peek() {
+            if c.is_whitespace() {
+                self.chars.next();
+            } else {
+                break;
+            }
         }
 
-        let start = self.index;
-        let ch = self.input.chars().nth(self.index).unwrap();
+        let (start, ch) = self.chars.next()?;
 
         let token_type = if ch.is_ascii_digit() || ch == '.' {
-            // Parse number
-            while self.index < self.input.len() {
-                let c = self.input.chars().nth(self.index).unwrap();
-                if c.is_ascii_digit() || c == '.' {
-                    self.index += 1;
-                } else {
-                    break;
-                }
+            match self.scan_number(start, ch) {
+                Ok(t) => t,
+                Err(e) => return Some(Err(e)),
             }
-            let literal = &self.input[start..self.index];
-            let num = literal.parse::<f32>().ok()?;
-            TokenType::Number(num)
         } else {
-            // Parse operator
-            let token_type = match ch {
+            match ch {
                 '+' => TokenType::Operator(OperatorType::Add),
                 '-' => TokenType::Operator(OperatorType::Subtract),
                 '*' => {
-                    let peek = self.peek();
-                    if peek == Some('*') {
-                        self.index += 1;
+                    if matches!(self.chars.peek(), Some(&(_, '*'))) {
+                        self.chars.next();
                         TokenType::Operator(OperatorType::Power)
                     } else {
                         TokenType::Operator(OperatorType::Multiply)
                     }
                 }
                 '/' => TokenType::Operator(OperatorType::Divide),
-                _ => return None,
-            };
-            self.index += 1;
-            token_type
+                '&' => TokenType::Operator(OperatorType::BitAnd),
+                '|' => TokenType::Operator(OperatorType::BitOr),
+                '^' => TokenType::Operator(OperatorType::BitXor),
+                '=' if matches!(self.chars.peek(), Some(&(_, '='))) => {
+                    self.chars.next();
+                    TokenType::Operator(OperatorType::Equal)
+                }
+                '!' if matches!(self.chars.peek(), Some(&(_, '='))) => {
+                    self.chars.next();
+                    TokenType::Operator(OperatorType::NotEqual)
+                }
+                '<' => {
+                    if matches!(self.chars.peek(), Some(&(_, '='))) {
+                        self.chars.next();
+                        TokenType::Operator(OperatorType::LessEqual)
+                    } else {
+                        TokenType::Operator(OperatorType::Less)
+                    }
+                }
+                '>' => {
+                    if matches!(self.chars.peek(), Some(&(_, '='))) {
+                        self.chars.next();
+                        TokenType::Operator(OperatorType::GreaterEqual)
+                    } else {
+                        TokenType::Operator(OperatorType::Greater)
+                    }
+                }
+                '(' => TokenType::LParen,
+                ')' => TokenType::RParen,
+                _ => return Some(Err(LexerError::IllegalChar { offset: start, ch })),
+            }
         };
 
-        let end = self.index;
+        let end = self.current_offset();
         let literal = &self.input[start..end];
 
-        Some(Token {
+        Some(Ok(Token {
             type_: token_type,
             start,
             end,
             literal,
-        })
+        }))
     }
 }
 
 impl<'a> Tokenizer<'a> {
-    pub fn peek(self: &Self) -> Option<char> {
-        self.input.chars().nth(self.index + 1)
-    }
-
-    pub fn tokenize(source: &'a str) -> Tokenizer<'a> {
+    pub fn new(source: &'a str) -> Tokenizer<'a> {
         Tokenizer {
             input: source,
-            index: 0,
+            chars: source.char_indices().peekable(),
+        }
+    }
+
+    /// Byte offset of the next unconsumed character, or the end of the
+    /// input if there isn't one.
+    fn current_offset(&mut self) -> usize {
+        self.chars
+            .peek()
+            .map(|&(i, _)| i)
+            .unwrap_or(self.input.len())
+    }
+
+    /// Scans a number literal starting at `start` with its first character
+    /// `first` (already known to be a digit or `.`). Recognizes `0x`/`0b`/
+    /// `0o` radix prefixes (case insensitive) in addition to the plain
+    /// decimal/float form, and allows `_` digit-group separators anywhere
+    /// in the digits, which are stripped before parsing.
+    fn scan_number(&mut self, start: usize, first: char) -> Result<TokenType, LexerError> {
+        if first == '0' {
+            let radix = match self.chars.peek() {
+                Some(&(_, 'x' | 'X')) => Some(16),
+                Some(&(_, 'b' | 'B')) => Some(2),
+                Some(&(_, 'o' | 'O')) => Some(8),
+                _ => None,
+            };
+            if let Some(radix) = radix {
+                self.chars.next(); // consume the radix letter
+                let mut digits = String::new();
+                while let Some(&(_, c)) = self.chars.peek() {
+                    if c == '_' {
+                        self.chars.next();
+                    } else if c.is_digit(radix) {
+                        digits.push(c);
+                        self.chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let end = self.current_offset();
+                if digits.is_empty() {
+                    return Err(LexerError::InvalidNumber { span: (start, end) });
+                }
+                return i64::from_str_radix(&digits, radix)
+                    .map(|v| TokenType::Number(v as f64))
+                    .map_err(|_| LexerError::InvalidNumber { span: (start, end) });
+            }
         }
+
+        let mut literal = String::new();
+        literal.push(first);
+        while let Some(&(_, c)) = self.chars.peek() {
+            if c.is_ascii_digit() || c == '.' {
+                literal.push(c);
+                self.chars.next();
+            } else if c == '_' {
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        literal.parse::<f64>().map(TokenType::Number).map_err(|_| {
+            let end = self.current_offset();
+            LexerError::InvalidNumber { span: (start, end) }
+        })
     }
 }
 
+/// Tokenize `source` in one pass, collecting every token or bailing out on
+/// the first lexer error. For streaming use (e.g. the REPL, which wants to
+/// parse without fully scanning first), construct a [`Tokenizer`] directly
+/// and consume it as an iterator.
+pub fn tokenize(source: &str) -> Result<Vec<Token<'_>>, LexerError> {
+    Tokenizer::new(source).collect()
+}
+
+/// An expression tree produced by the parser. Kept separate from token
+/// types so it can be printed, evaluated, or transformed independently of
+/// parsing.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Unary {
+        op: OperatorType,
+        operand: Box<Expr>,
+    },
+    BinaryOp {
+        op: OperatorType,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+}
+
+/// Left/right binding power for an infix operator. Parsing continues
+/// folding into the left-hand side while the next operator's left binding
+/// power is at least the caller's minimum. Right-associativity (`**`)
+/// falls out of giving the right binding power a lower value than the
+/// left one. From loosest to tightest: comparison, bitwise, `+`/`-`,
+/// `*`/`/`, `**`.
+fn binding_power(op: OperatorType) -> (u8, u8) {
+    use OperatorType::*;
+    match op {
+        Equal | NotEqual | Less | LessEqual | Greater | GreaterEqual => (1, 2),
+        BitAnd | BitOr | BitXor => (3, 4),
+        Add | Subtract => (5, 6),
+        Multiply | Divide => (7, 8),
+        Power => (10, 9),
+    }
+}
+
+/// Binding power a unary prefix operator parses its operand at. Chosen
+/// between `*`/`/` (7, 8) and `**` (10, 9) so that `-2 ** 2` parses as
+/// `-(2 ** 2)` (unary binds looser than `**`) while `-2 * 3` parses as
+/// `(-2) * 3` (unary binds tighter than `*`/`/`).
+const UNARY_BP: u8 = 9;
+
 pub struct Parser<'a> {
     pub tokenizer: std::iter::Peekable<Tokenizer<'a>>,
 }
 
 impl<'a> Parser<'a> {
     pub fn accept(self: &mut Self, check: impl Fn(TokenType) -> bool) -> Option<Token<'a>> {
-        if let Some(token) = self.tokenizer.peek() {
+        if let Some(Ok(token)) = self.tokenizer.peek() {
             if check(token.type_) {
-                return self.tokenizer.next();
+                return self.tokenizer.next().and_then(|r| r.ok());
             }
         }
         None
@@ -158,86 +324,279 @@ impl<'a> Parser<'a> {
         Err(anyhow!("unexpected token"))
     }
 
-    /// a factor is either:
-    /// a number
-    pub fn factor(self: &mut Self) -> Result<f32> {
-        if let Some(token) = self.tokenizer.next() {
-            match token.type_ {
-                TokenType::Number(n) => Ok(n),
+    /// Prefix position: a bare number, a unary `+`/`-` applied to whatever
+    /// binds at `UNARY_BP`, or a parenthesized sub-expression.
+    fn parse_prefix(self: &mut Self) -> Result<Expr> {
+        match self.tokenizer.next() {
+            Some(Err(e)) => Err(e.into()),
+            Some(Ok(token)) => match token.type_ {
+                TokenType::Number(n) => Ok(Expr::Number(n)),
+                TokenType::Operator(op @ (OperatorType::Add | OperatorType::Subtract)) => {
+                    let operand = self.parse_expr(UNARY_BP)?;
+                    Ok(Expr::Unary {
+                        op,
+                        operand: Box::new(operand),
+                    })
+                }
+                TokenType::LParen => {
+                    let inner = self.parse_expr(0)?;
+                    self.except(|t| matches!(t, TokenType::RParen))
+                        .map_err(|_| {
+                            anyhow!(
+                                "unbalanced parentheses: no matching ')' for '(' at byte {}",
+                                token.start
+                            )
+                        })?;
+                    Ok(inner)
+                }
                 _ => Err(anyhow!("expected number, got {}", token.type_)),
-            }
-        } else {
-            Err(anyhow!("unexpected end of input"))
+            },
+            None => Err(anyhow!("unexpected end of input")),
         }
     }
 
-    pub fn expo(self: &mut Self) -> Result<f32> {
-        use OperatorType::*;
-        use TokenType::*;
-        let mut base = self.factor()?;
-        while let Some(op) = self.accept(|t| matches!(t, Operator(Power))) {
-            let exponent = self.factor()?;
-            base = match op.type_ {
-                Operator(Power) => base.powf(exponent),
-                _ => unreachable!(),
+    /// Precedence-climbing core: parse a prefix expression, then keep
+    /// folding in infix operators whose left binding power is at least
+    /// `min_bp`, recursing on the right-hand side with that operator's
+    /// right binding power.
+    pub fn parse_expr(self: &mut Self, min_bp: u8) -> Result<Expr> {
+        let mut left = self.parse_prefix()?;
+
+        loop {
+            let op = match self.tokenizer.peek() {
+                Some(Ok(Token {
+                    type_: TokenType::Operator(op),
+                    ..
+                })) => *op,
+                Some(Err(_)) => {
+                    let err = self.tokenizer.next().unwrap().unwrap_err();
+                    return Err(err.into());
+                }
+                _ => break,
             };
-        }
-        Ok(base)
-    }
-
-    /// a term is:
-    /// factor (* | /) factor (* | /) factor ...
-    pub fn term(self: &mut Self) -> Result<f32> {
-        use OperatorType::*;
-        use TokenType::*;
-        let mut left = self.expo()?;
-        while let Some(op) = self.accept(|t| matches!(t, Operator(Multiply) | Operator(Divide))) {
-            let right = self.expo()?;
-            left = match op.type_ {
-                Operator(Multiply) => left * right,
-                Operator(Divide) => left / right,
-                _ => unreachable!(),
+
+            let (left_bp, right_bp) = binding_power(op);
+            if left_bp < min_bp {
+                break;
+            }
+
+            self.tokenizer.next();
+            let right = self.parse_expr(right_bp)?;
+            left = Expr::BinaryOp {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
             };
         }
+
         Ok(left)
     }
 
-    /// an expression is:
-    /// term (+ | -) term (+ | -) term ...
-    pub fn expression(self: &mut Self) -> Result<f32> {
-        use OperatorType::*;
-        use TokenType::*;
-        let mut left = self.term()?;
-        while let Some(op) = self.accept(|t| matches!(t, Operator(Add) | Operator(Subtract))) {
-            let right = self.term()?;
-            left = match op.type_ {
-                Operator(Add) => left + right,
-                Operator(Subtract) => left - right,
-                _ => unreachable!(),
-            };
+    /// Parses a complete expression and ensures nothing is left over
+    /// afterwards, so a stray trailing token (e.g. an unmatched `)`, or
+    /// two expressions mashed together) is reported instead of silently
+    /// dropped.
+    pub fn expression(self: &mut Self) -> Result<Expr> {
+        let expr = self.parse_expr(0)?;
+        match self.tokenizer.next() {
+            None => Ok(expr),
+            Some(Ok(token)) => Err(anyhow!(
+                "unexpected trailing token {} at byte {}",
+                token.type_,
+                token.start
+            )),
+            Some(Err(e)) => Err(e.into()),
         }
-        Ok(left)
     }
 }
 
-fn main() -> Result<()> {
+/// The result of evaluating an expression: arithmetic yields `Number`,
+/// comparisons yield `Bool`, and bitwise operators yield `Int`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Int(i64),
+    Bool(bool),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Int(i) => write!(f, "{}", i),
+            Value::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+/// Coerces a `Value` to a number for arithmetic/comparison; `Bool` has no
+/// sensible numeric reading.
+fn as_number(value: Value) -> Result<f64> {
+    match value {
+        Value::Number(n) => Ok(n),
+        Value::Int(i) => Ok(i as f64),
+        Value::Bool(b) => Err(anyhow!("expected a number, got bool {}", b)),
+    }
+}
+
+/// Coerces a `Value` to an integer for bitwise operators, which need a
+/// genuinely integer-valued operand.
+fn as_int(value: Value) -> Result<i64> {
+    match value {
+        Value::Int(i) => Ok(i),
+        Value::Number(n) if n.fract() == 0.0 => Ok(n as i64),
+        Value::Number(n) => Err(anyhow!(
+            "bitwise operators require integer-valued operands, got {}",
+            n
+        )),
+        Value::Bool(b) => Err(anyhow!(
+            "bitwise operators require integer-valued operands, got bool {}",
+            b
+        )),
+    }
+}
+
+/// Evaluate an expression tree to a single value.
+pub fn eval(expr: &Expr) -> Result<Value> {
+    match expr {
+        Expr::Number(n) => Ok(Value::Number(*n)),
+        Expr::Unary { op, operand } => {
+            let operand = eval(operand)?;
+            match op {
+                OperatorType::Add => Ok(operand),
+                OperatorType::Subtract => match operand {
+                    Value::Number(n) => Ok(Value::Number(-n)),
+                    Value::Int(i) => Ok(Value::Int(-i)),
+                    Value::Bool(b) => Err(anyhow!("cannot negate bool {}", b)),
+                },
+                _ => unreachable!("tokenizer/parser only ever build Unary with Add or Subtract"),
+            }
+        }
+        Expr::BinaryOp { op, left, right } => {
+            use OperatorType::*;
+            let left = eval(left)?;
+            let right = eval(right)?;
+            match op {
+                Add | Subtract | Multiply | Divide | Power => {
+                    let left = as_number(left)?;
+                    let right = as_number(right)?;
+                    Ok(Value::Number(match op {
+                        Add => left + right,
+                        Subtract => left - right,
+                        Multiply => left * right,
+                        Divide => left / right,
+                        Power => left.powf(right),
+                        _ => unreachable!(),
+                    }))
+                }
+                Equal | NotEqual | Less | LessEqual | Greater | GreaterEqual => {
+                    let left = as_number(left)?;
+                    let right = as_number(right)?;
+                    Ok(Value::Bool(match op {
+                        Equal => left == right,
+                        NotEqual => left != right,
+                        Less => left < right,
+                        LessEqual => left <= right,
+                        Greater => left > right,
+                        GreaterEqual => left >= right,
+                        _ => unreachable!(),
+                    }))
+                }
+                BitAnd | BitOr | BitXor => {
+                    let left = as_int(left)?;
+                    let right = as_int(right)?;
+                    Ok(Value::Int(match op {
+                        BitAnd => left & right,
+                        BitOr => left | right,
+                        BitXor => left ^ right,
+                        _ => unreachable!(),
+                    }))
+                }
+            }
+        }
+    }
+}
+
+/// Tokenizes, parses, and evaluates a single expression.
+fn eval_line(line: &str) -> Result<Value> {
+    let tokenizer = Tokenizer::new(line).peekable();
+    let mut parser = Parser { tokenizer };
+    let expr = parser.expression()?;
+    eval(&expr)
+}
+
+/// Interactive mode: keep prompting even after a parse/eval error, only
+/// exiting on `q`/`quit` or end of input.
+fn run_repl() -> Result<()> {
     let mut buf = String::new();
     loop {
         print!("> ");
         std::io::stdout().flush()?;
-        _ = std::io::stdin().read_line(&mut buf)?;
+        buf.clear();
+        if std::io::stdin().read_line(&mut buf)? == 0 {
+            return Ok(());
+        }
 
         // exit on q or quit
-        if &buf == "q\n" || &buf == "quit\n" {
+        if buf.trim() == "q" || buf.trim() == "quit" {
             return Ok(());
         }
 
-        let tokenizer = Tokenizer::tokenize(&buf).peekable();
-        let mut parser = Parser { tokenizer };
+        match eval_line(&buf) {
+            Ok(value) => println!("{}", value),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+}
 
-        let result = parser.expression()?;
-        println!("{}", result);
-        buf.clear();
+/// `-e EXPR` mode: evaluate a single expression and print the result.
+/// Exits non-zero on a parse/eval error.
+fn run_inline(expr: &str) -> Result<()> {
+    match eval_line(expr) {
+        Ok(value) => {
+            println!("{}", value);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// File mode: evaluate each non-blank line of `path` as its own
+/// expression. Exits non-zero if any line failed to parse/eval.
+fn run_file(path: &str) -> Result<()> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut had_error = false;
+
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match eval_line(line) {
+            Ok(value) => println!("{}", value),
+            Err(e) => {
+                eprintln!("{}", e);
+                had_error = true;
+            }
+        }
+    }
+
+    if had_error {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => run_repl(),
+        [flag] if flag == "-e" => Err(anyhow!("usage: mini-lexer -e EXPR (missing expression)")),
+        [flag, expr] if flag == "-e" => run_inline(expr),
+        [path] => run_file(path),
+        _ => Err(anyhow!("usage: mini-lexer [FILE | -e EXPR]")),
     }
 }
 
@@ -248,8 +607,11 @@ mod tests {
     #[test]
     fn test_tokenize() {
         let input = "2 ** 2 + 4 * 5";
-        let tokenizer = Tokenizer::tokenize(&input).peekable();
-        let tokens = tokenizer.into_iter().collect::<Vec<Token>>();
+        let tokenizer = Tokenizer::new(&input).peekable();
+        let tokens = tokenizer
+            .into_iter()
+            .map(|t| t.unwrap())
+            .collect::<Vec<Token>>();
         assert_eq!(
             tokens,
             vec![
@@ -298,4 +660,207 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_parse_and_eval_precedence() {
+        let tokenizer = Tokenizer::new("2 ** 2 + 4 * 5").peekable();
+        let mut parser = Parser { tokenizer };
+        let expr = parser.expression().unwrap();
+        assert_eq!(eval(&expr).unwrap(), Value::Number(24.0));
+    }
+
+    #[test]
+    fn test_unary_minus() {
+        let tokenizer = Tokenizer::new("-5").peekable();
+        let mut parser = Parser { tokenizer };
+        let expr = parser.expression().unwrap();
+        assert_eq!(eval(&expr).unwrap(), Value::Number(-5.0));
+    }
+
+    #[test]
+    fn test_double_unary_minus() {
+        let tokenizer = Tokenizer::new("- -3").peekable();
+        let mut parser = Parser { tokenizer };
+        let expr = parser.expression().unwrap();
+        assert_eq!(eval(&expr).unwrap(), Value::Number(3.0));
+    }
+
+    #[test]
+    fn test_unary_minus_binds_looser_than_power() {
+        let tokenizer = Tokenizer::new("-2 ** 2").peekable();
+        let mut parser = Parser { tokenizer };
+        let expr = parser.expression().unwrap();
+        assert_eq!(eval(&expr).unwrap(), Value::Number(-4.0));
+    }
+
+    #[test]
+    fn test_hex_binary_octal_literals() {
+        let tokenizer = Tokenizer::new("0xFF").peekable();
+        let mut parser = Parser { tokenizer };
+        assert_eq!(
+            eval(&parser.expression().unwrap()).unwrap(),
+            Value::Number(255.0)
+        );
+
+        let tokenizer = Tokenizer::new("0b101").peekable();
+        let mut parser = Parser { tokenizer };
+        assert_eq!(
+            eval(&parser.expression().unwrap()).unwrap(),
+            Value::Number(5.0)
+        );
+
+        let tokenizer = Tokenizer::new("0o17").peekable();
+        let mut parser = Parser { tokenizer };
+        assert_eq!(
+            eval(&parser.expression().unwrap()).unwrap(),
+            Value::Number(15.0)
+        );
+    }
+
+    #[test]
+    fn test_digit_group_separators() {
+        let tokenizer = Tokenizer::new("1_000 + 0x1_00").peekable();
+        let mut parser = Parser { tokenizer };
+        assert_eq!(
+            eval(&parser.expression().unwrap()).unwrap(),
+            Value::Number(1256.0)
+        );
+    }
+
+    #[test]
+    fn test_illegal_char_reports_offset() {
+        let mut tokenizer = Tokenizer::new("1 @ 2");
+        assert_eq!(
+            tokenizer.next(),
+            Some(Ok(Token {
+                type_: TokenType::Number(1.0),
+                start: 0,
+                end: 1,
+                literal: "1",
+            }))
+        );
+        assert_eq!(
+            tokenizer.next(),
+            Some(Err(LexerError::IllegalChar { offset: 2, ch: '@' }))
+        );
+    }
+
+    #[test]
+    fn test_tokenize_entry_point_collects_all_tokens() {
+        let tokens = tokenize("1 + 2").unwrap();
+        assert_eq!(tokens.len(), 3);
+    }
+
+    #[test]
+    fn test_empty_radix_literal_is_a_lexer_error() {
+        let mut tokenizer = Tokenizer::new("0x");
+        assert_eq!(
+            tokenizer.next(),
+            Some(Err(LexerError::InvalidNumber { span: (0, 2) }))
+        );
+    }
+
+    #[test]
+    fn test_parenthesized_grouping() {
+        let tokenizer = Tokenizer::new("(2 + 3) * 4").peekable();
+        let mut parser = Parser { tokenizer };
+        let expr = parser.expression().unwrap();
+        assert_eq!(eval(&expr).unwrap(), Value::Number(20.0));
+    }
+
+    #[test]
+    fn test_unbalanced_parentheses_reports_offset() {
+        let tokenizer = Tokenizer::new("(2 + 3").peekable();
+        let mut parser = Parser { tokenizer };
+        let err = parser.expression().unwrap_err();
+        assert!(err.to_string().contains("byte 0"));
+    }
+
+    #[test]
+    fn test_stray_closing_paren_is_an_error() {
+        let tokenizer = Tokenizer::new("2 + 3)").peekable();
+        let mut parser = Parser { tokenizer };
+        let err = parser.expression().unwrap_err();
+        assert!(err.to_string().contains("byte 5"));
+    }
+
+    #[test]
+    fn test_power_is_right_associative() {
+        let tokenizer = Tokenizer::new("2 ** 3 ** 2").peekable();
+        let mut parser = Parser { tokenizer };
+        let expr = parser.expression().unwrap();
+        // 2 ** (3 ** 2) = 512, not (2 ** 3) ** 2 = 64
+        assert_eq!(eval(&expr).unwrap(), Value::Number(512.0));
+    }
+
+    #[test]
+    fn test_comparison_operators() {
+        for (input, expected) in [
+            ("1 + 1 == 2", true),
+            ("1 == 2", false),
+            ("1 != 2", true),
+            ("1 < 2", true),
+            ("2 <= 2", true),
+            ("3 > 2", true),
+            ("2 >= 3", false),
+        ] {
+            let tokenizer = Tokenizer::new(input).peekable();
+            let mut parser = Parser { tokenizer };
+            let expr = parser.expression().unwrap();
+            assert_eq!(eval(&expr).unwrap(), Value::Bool(expected), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_bitwise_operators() {
+        let tokenizer = Tokenizer::new("6 & 3 | 8 ^ 1").peekable();
+        let mut parser = Parser { tokenizer };
+        let expr = parser.expression().unwrap();
+        // no arithmetic or comparison here; all three ops share the
+        // bitwise tier and are left-associative:
+        // ((6 & 3) | 8) ^ 1 = (2 | 8) ^ 1 = 10 ^ 1 = 11
+        assert_eq!(eval(&expr).unwrap(), Value::Int(11));
+    }
+
+    #[test]
+    fn test_bitwise_is_looser_than_arithmetic_and_tighter_than_comparison() {
+        let tokenizer = Tokenizer::new("1 + 2 & 2 == 2").peekable();
+        let mut parser = Parser { tokenizer };
+        let expr = parser.expression().unwrap();
+        // Parses as ((1 + 2) & 2) == 2, i.e. (3 & 2) == 2, which is true.
+        // With `&` tighter than `+` it would be (1 + (2 & 2)) == 2, i.e.
+        // 3 == 2, which is false; with `==` tighter than `&` it would be
+        // 3 & (2 == 2), a bitwise op on a bool, which is an error.
+        assert_eq!(eval(&expr).unwrap(), Value::Bool(true));
+    }
+
+    #[test]
+    fn test_bitwise_on_non_integer_is_an_error() {
+        let tokenizer = Tokenizer::new("1.5 & 2").peekable();
+        let mut parser = Parser { tokenizer };
+        let expr = parser.expression().unwrap();
+        assert!(eval(&expr).is_err());
+    }
+
+    #[test]
+    fn test_value_display() {
+        assert_eq!(Value::Number(2.5).to_string(), "2.5");
+        assert_eq!(Value::Int(7).to_string(), "7");
+        assert_eq!(Value::Bool(true).to_string(), "true");
+    }
+
+    #[test]
+    fn test_eval_line() {
+        assert_eq!(eval_line("2 + 3 * 4").unwrap(), Value::Number(14.0));
+        assert!(eval_line("2 +").is_err());
+    }
+
+    #[test]
+    fn test_eval_line_rejects_trailing_tokens() {
+        // A second, unconsumed token after a syntactically valid
+        // expression must be an error, not silently ignored.
+        assert!(eval_line("2 + 3 4").is_err());
+        // "0x1" parses as a complete expression, leaving ".5" dangling.
+        assert!(eval_line("0x1.5").is_err());
+    }
 }